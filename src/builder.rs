@@ -0,0 +1,149 @@
+use log::Level;
+
+use crate::{error::Result, LogServer, Settings};
+
+/// Builds a [`LogServer`] from either a `lotwire.toml` config file or manual values,
+/// returning structured errors from [`LogServerBuilder::build`] instead of panicking.
+///
+/// This is the preferred construction path; [`LogServer::new`] and
+/// [`LogServer::with_settings`] remain as panicking convenience wrappers around it.
+#[derive(Debug, Default)]
+pub struct LogServerBuilder {
+    config: Option<(String, String)>,
+    address: Option<String>,
+    port: Option<u32>,
+    level: Option<Level>,
+    num_records: Option<u32>,
+    extended: Option<bool>,
+    log_file: Option<String>,
+    use_syslog: Option<bool>,
+    reuse: Option<bool>,
+    api_keys: Option<Vec<String>>,
+}
+
+impl LogServerBuilder {
+    /// Starts a builder that loads its settings from the given `lotwire.toml` file.
+    /// Values set via the other builder methods override the loaded config.
+    pub fn from_config(dir: &str, filename: &str) -> Self {
+        Self {
+            config: Some((dir.to_string(), filename.to_string())),
+            ..Default::default()
+        }
+    }
+
+    /// Starts a builder pre-populated with the given [`Settings`].
+    pub fn from_settings(settings: Settings) -> Self {
+        Self {
+            config: None,
+            address: Some(settings.address),
+            port: Some(settings.port),
+            level: Some(settings.level),
+            num_records: Some(settings.num_records),
+            extended: Some(settings.extended),
+            log_file: settings.log_file,
+            use_syslog: Some(settings.use_syslog),
+            reuse: Some(settings.reuse),
+            api_keys: Some(settings.api_keys),
+        }
+    }
+
+    /// Sets the address to listen on, overriding any value loaded from a config file.
+    pub fn address(mut self, address: &str) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    /// Sets the HTTP port to listen on, overriding any value loaded from a config file.
+    pub fn port(mut self, port: u32) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the minimum log level to capture, overriding any value loaded from a config file.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the max record size of the in-memory ring buffer.
+    pub fn num_records(mut self, num_records: u32) -> Self {
+        self.num_records = Some(num_records);
+        self
+    }
+
+    /// Enables extended logging: captures the source `file` and `line` of each log record.
+    pub fn extended(mut self, extended: bool) -> Self {
+        self.extended = Some(extended);
+        self
+    }
+
+    /// Configures a file that formatted log records are appended to.
+    pub fn log_file(mut self, log_file: &str) -> Self {
+        self.log_file = Some(log_file.to_string());
+        self
+    }
+
+    /// Enables forwarding formatted log records to the system logger (syslog).
+    pub fn use_syslog(mut self, use_syslog: bool) -> Self {
+        self.use_syslog = Some(use_syslog);
+        self
+    }
+
+    /// When `address` is a `unix:` socket path, controls whether lotwire manages
+    /// the socket file's lifecycle. Has no effect for TCP addresses.
+    pub fn reuse(mut self, reuse: bool) -> Self {
+        self.reuse = Some(reuse);
+        self
+    }
+
+    /// Requires one of `api_keys` on the `Authorization`/`X-Api-Key` header of every
+    /// request to the log endpoints, overriding any value loaded from a config file.
+    pub fn api_keys(mut self, api_keys: Vec<String>) -> Self {
+        self.api_keys = Some(api_keys);
+        self
+    }
+
+    fn resolve_settings(self) -> Result<Settings> {
+        let mut settings = match self.config {
+            Some((dir, filename)) => Settings::try_new(&dir, &filename)?,
+            None => Settings::with_values("127.0.0.1", 8080, Level::Error, 50),
+        };
+
+        if let Some(address) = self.address {
+            settings.address = address;
+        }
+        if let Some(port) = self.port {
+            settings.port = port;
+        }
+        if let Some(level) = self.level {
+            settings.level = level;
+        }
+        if let Some(num_records) = self.num_records {
+            settings.num_records = num_records;
+        }
+        if let Some(extended) = self.extended {
+            settings.extended = extended;
+        }
+        if self.log_file.is_some() {
+            settings.log_file = self.log_file;
+        }
+        if let Some(use_syslog) = self.use_syslog {
+            settings.use_syslog = use_syslog;
+        }
+        if let Some(reuse) = self.reuse {
+            settings.reuse = reuse;
+        }
+        if let Some(api_keys) = self.api_keys {
+            settings.api_keys = api_keys;
+        }
+
+        Ok(settings)
+    }
+
+    /// Resolves the settings and constructs the [`LogServer`], opening the log file
+    /// and connecting to syslog if configured.
+    pub fn build(self) -> Result<LogServer> {
+        let settings = self.resolve_settings()?;
+        LogServer::try_init(settings)
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+/// Errors that can occur while configuring or starting a [`crate::LogServer`].
+#[derive(Debug, Error)]
+pub enum LotwireError {
+    /// The settings file could not be found, read, or parsed.
+    #[error("could not load settings: {0}")]
+    ConfigNotFound(#[from] config::ConfigError),
+
+    /// The configured listen address could not be parsed.
+    #[error("invalid listen address `{0}`")]
+    InvalidAddress(String),
+
+    /// The configured log level string did not match a known `log::Level`.
+    #[error("invalid log level `{0}`")]
+    InvalidLevel(String),
+
+    /// A logger has already been installed for this process via `log::set_boxed_logger`.
+    #[error("a logger is already installed for this process")]
+    LoggerAlreadySet,
+
+    /// The configured log file could not be opened for appending.
+    #[error("could not open log file `{path}`: {source}")]
+    LogFile {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The HTTP server failed to bind or encountered an error while running.
+    #[error("log server failed to start: {0}")]
+    BindFailed(#[from] rocket::Error),
+
+    /// The Unix domain socket listener could not be bound.
+    #[error("could not bind unix socket `{path}`: {source}")]
+    UnixSocketBind {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A `unix:` address was configured on a platform without Unix domain socket support.
+    #[error("unix domain sockets are not supported on this platform (`{0}`)")]
+    UnixSocketUnsupported(String),
+}
+
+/// A convenience alias for `Result<T, LotwireError>`.
+pub type Result<T> = std::result::Result<T, LotwireError>;
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use config::Config;
 use lazy_static::lazy_static;
 use log::{Level, LevelFilter, Log};
@@ -6,66 +7,102 @@ use rocket::{
     fairing::{Fairing, Info, Kind},
     get,
     http::{Header, Status},
+    request::{FromRequest, Outcome},
+    response::stream::{Event, EventStream},
     routes,
     serde::json::Json,
-    Request, Response, State,
+    tokio::{select, sync::broadcast},
+    Request, Response, Shutdown, State,
 };
 use std::{
+    fs::{File, OpenOptions},
+    io::Write,
     path::Path,
     sync::{Arc, Mutex},
     thread,
 };
+use syslog::{Facility, Formatter3164};
+
+mod builder;
+mod error;
+
+pub use builder::LogServerBuilder;
+pub use error::{LotwireError, Result};
+
+/// The capacity of the broadcast channel used to fan out new `LogItem`s to
+/// connected `/logs/stream` subscribers. This is independent of the ring
+/// buffer size; a slow subscriber that falls behind simply misses the
+/// oldest lagged messages rather than blocking logging.
+const STREAM_CHANNEL_CAPACITY: usize = 1024;
 
 /// Represents settings for the LogServer. See the `new` or `with_values` function for more information.
 #[derive(Debug, Clone)]
 pub struct Settings {
-    address: String,
-    port: u32,
-    level: Level,
-    num_records: u32,
+    pub(crate) address: String,
+    pub(crate) port: u32,
+    pub(crate) level: Level,
+    pub(crate) num_records: u32,
+    pub(crate) extended: bool,
+    pub(crate) log_file: Option<String>,
+    pub(crate) use_syslog: bool,
+    pub(crate) reuse: bool,
+    pub(crate) api_keys: Vec<String>,
 }
 
 impl Settings {
     /// Creates new settings from the specified `lotwire.toml` file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be found or parsed, or if it contains an invalid
+    /// value. Prefer [`Settings::try_new`] or [`LogServerBuilder`] to handle these
+    /// cases without aborting the process.
     pub fn new(dir: &str, filename: &str) -> Self {
-        let location = Path::new(dir).join(filename.clone());
-        let location = location.to_str().unwrap();
-        let error_message = "Could not find settings".to_string();
+        Self::try_new(dir, filename).expect("invalid lotwire settings")
+    }
+
+    /// Fallible counterpart to [`Settings::new`].
+    pub fn try_new(dir: &str, filename: &str) -> Result<Self> {
+        let location = Path::new(dir).join(filename);
+        let location = location.to_str().expect("settings path is not valid UTF-8");
 
         let settings = Config::builder()
             .add_source(config::File::with_name(location))
             .add_source(config::Environment::with_prefix("APP"))
-            .build()
-            .expect(&error_message);
-
-        let address = settings.get_string("address").unwrap();
-        let port = settings.get_int("port").unwrap() as u32;
-        let num_records = settings.get_int("num_messages").unwrap() as u32;
-        let str_level = settings.get_string("level").unwrap();
-        let str_level = str_level.as_str();
-
-        let level = match str_level {
-            "error" => Level::Error,
-            "warn" => Level::Warn,
-            "info" => Level::Info,
-            "debug" => Level::Debug,
-            "trace" => Level::Trace,
-            _ => Level::Error,
-        };
-
-        Self {
+            .build()?;
+
+        let address = settings.get_string("address")?;
+        let port = settings.get_int("port")? as u32;
+        let num_records = settings.get_int("num_messages")? as u32;
+        let level = parse_level(&settings.get_string("level")?)?;
+
+        let extended = settings.get_bool("extended").unwrap_or(false);
+        let log_file = settings.get_string("log_file").ok();
+        let use_syslog = settings.get_bool("use_syslog").unwrap_or(false);
+        let reuse = settings.get_bool("reuse").unwrap_or(false);
+        let api_keys = settings
+            .get::<Vec<String>>("api_keys")
+            .unwrap_or_default();
+
+        Ok(Self {
             address,
             port,
             level,
             num_records,
-        }
+            extended,
+            log_file,
+            use_syslog,
+            reuse,
+            api_keys,
+        })
     }
 
     /// Optionally configure Settings with manual values instead of from a `lotwire.toml` file.
-    /// 
+    ///
     /// Values are:
-    /// - address: The address to serve messages from
-    /// - port: the HTTP port
+    /// - address: The address to serve messages from. A `unix:/path/to/socket` value
+    ///   binds a Unix domain socket at that path instead of listening over TCP.
+    /// - port: the HTTP port (ignored when `address` is a `unix:` socket path)
     /// - level: the minimum log level you wish to capture
     /// - num_records: the max record size of the buffer
     pub fn with_values(address: &str, port: u32, level: Level, num_records: u32) -> Self {
@@ -74,8 +111,67 @@ impl Settings {
             port,
             level,
             num_records,
+            extended: false,
+            log_file: None,
+            use_syslog: false,
+            reuse: false,
+            api_keys: Vec::new(),
         }
     }
+
+    /// Enables extended logging: captures the source `file` and `line` of each
+    /// log record in addition to the level, module, and message.
+    pub fn with_extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// Configures a file that formatted log records are appended to, in addition
+    /// to being kept in the in-memory ring buffer.
+    pub fn with_log_file(mut self, log_file: &str) -> Self {
+        self.log_file = Some(log_file.to_string());
+        self
+    }
+
+    /// Enables forwarding formatted log records to the system logger (syslog).
+    pub fn with_syslog(mut self, use_syslog: bool) -> Self {
+        self.use_syslog = use_syslog;
+        self
+    }
+
+    /// When `address` is a `unix:` socket path, controls whether lotwire manages
+    /// the socket file's lifecycle: removing a stale file before binding, and
+    /// removing the file again once the server stops. Has no effect for TCP addresses.
+    pub fn with_reuse(mut self, reuse: bool) -> Self {
+        self.reuse = reuse;
+        self
+    }
+
+    /// Requires one of `api_keys` on the `Authorization`/`X-Api-Key` header of every
+    /// request to the log endpoints. When empty (the default), the endpoints stay
+    /// open, preserving the previous unauthenticated behavior.
+    pub fn with_api_keys(mut self, api_keys: Vec<String>) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+}
+
+/// Returns the filesystem path from a `unix:/path/to/socket` style address, or
+/// `None` if `address` is a regular host/IP address.
+fn unix_socket_path(address: &str) -> Option<&str> {
+    address.strip_prefix("unix:")
+}
+
+/// Parses a `level` string from a config file into a `log::Level`.
+fn parse_level(value: &str) -> Result<Level> {
+    match value {
+        "error" => Ok(Level::Error),
+        "warn" => Ok(Level::Warn),
+        "info" => Ok(Level::Info),
+        "debug" => Ok(Level::Debug),
+        "trace" => Ok(Level::Trace),
+        other => Err(LotwireError::InvalidLevel(other.to_string())),
+    }
 }
 
 lazy_static! {
@@ -91,6 +187,20 @@ lazy_static! {
 pub struct LogServer {
     settings: Option<Settings>,
     buffer: Option<Arc<Mutex<AllocRingBuffer<LogItem>>>>,
+    sender: Option<Arc<broadcast::Sender<LogItem>>>,
+    log_file: Option<Arc<Mutex<File>>>,
+    syslog: Option<SyslogSink>,
+}
+
+/// A `Clone`/`Debug`-able handle to the system logger connection. The underlying
+/// `syslog::Logger` doesn't implement those traits itself, so it's wrapped here.
+#[derive(Clone)]
+struct SyslogSink(Arc<Mutex<syslog::Logger<syslog::LoggerBackend, Formatter3164>>>);
+
+impl std::fmt::Debug for SyslogSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SyslogSink")
+    }
 }
 
 /// Represents a log messgae.
@@ -99,39 +209,107 @@ pub struct LogItem {
     pub level: String,
     pub module: String,
     pub message: String,
+    /// RFC3339 timestamp captured when the record was logged.
+    pub timestamp: String,
+    /// Source file the record was logged from, when extended logging is enabled.
+    pub file: Option<String>,
+    /// Source line the record was logged from, when extended logging is enabled.
+    pub line: Option<u32>,
 }
 
 impl LogServer {
+    /// Starts building a [`LogServer`] via [`LogServerBuilder`], the preferred
+    /// construction path for handling bad config gracefully.
+    pub fn builder() -> LogServerBuilder {
+        LogServerBuilder::default()
+    }
+
     /// Configures the server with the specified `lotwire.toml` file.
+    ///
+    /// # Panics
+    ///
+    /// Panics on a missing/invalid config file or if a configured log file cannot
+    /// be opened. Prefer [`LogServer::builder`] to handle these cases without
+    /// aborting the process. The address is not parsed here; an unparseable one
+    /// is reported by the server thread as [`LotwireError::InvalidAddress`].
     pub fn new(dir: &str, filename: &str) -> LogServer {
-        let settings = Settings::new(dir, filename);
-        Self::init(settings)
+        LogServerBuilder::from_config(dir, filename)
+            .build()
+            .expect("failed to initialize LogServer")
     }
 
     /// Configures the server with the specified settings.
-    /// 
+    ///
     /// NOTE: You _must_ call `init_logger` to register the server
     /// with your logging facade; otherwise no logs will be captured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a configured log file cannot be opened. Prefer
+    /// [`LogServer::builder`] to handle this case without aborting the process.
     pub fn with_settings(settings: Settings) -> LogServer {
-        Self::init(settings)
+        LogServerBuilder::from_settings(settings)
+            .build()
+            .expect("failed to initialize LogServer")
     }
 
-    fn init(settings: Settings) -> LogServer {
+    /// Fallible counterpart to the `new`/`with_settings` constructors, used by
+    /// [`LogServerBuilder::build`].
+    pub(crate) fn try_init(settings: Settings) -> Result<LogServer> {
         let buffer = AllocRingBuffer::new(settings.num_records as usize);
         let buffer = Mutex::new(buffer);
 
+        let (sender, _) = broadcast::channel(STREAM_CHANNEL_CAPACITY);
+
+        let log_file = match settings.log_file.as_ref() {
+            Some(path) => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|source| LotwireError::LogFile {
+                        path: path.clone(),
+                        source,
+                    })?;
+                Some(Arc::new(Mutex::new(file)))
+            }
+            None => None,
+        };
+
+        let syslog = if settings.use_syslog {
+            let formatter = Formatter3164 {
+                facility: Facility::LOG_USER,
+                hostname: None,
+                process: "lotwire".into(),
+                pid: std::process::id(),
+            };
+
+            match syslog::unix(formatter) {
+                Ok(logger) => Some(SyslogSink(Arc::new(Mutex::new(logger)))),
+                Err(err) => {
+                    eprintln!("lotwire: failed to connect to syslog: {err}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         let server = Self {
             settings: Some(settings),
             buffer: Some(buffer.into()),
+            sender: Some(sender.into()),
+            log_file,
+            syslog,
         };
 
         *SERVER.lock().unwrap() = server.clone();
-        server
+        Ok(server)
     }
 
     /// Registers the server with your logging facade to start recording.
-    pub fn init_logger(&self) {
-        let settings = self.settings.as_ref().unwrap().clone();
+    pub fn init_logger(&self) -> Result<()> {
+        let settings = self.settings.as_ref().expect("LogServer missing settings").clone();
 
         let max_level = match settings.level {
             Level::Error => LevelFilter::Error,
@@ -142,45 +320,87 @@ impl LogServer {
         };
 
         log::set_max_level(max_level);
-        log::set_boxed_logger(Box::new(self.clone())).unwrap();
+        log::set_boxed_logger(Box::new(self.clone())).map_err(|_| LotwireError::LoggerAlreadySet)?;
+
+        Ok(())
     }
 
-    /// Starts the LogServer's HTTP server.
-    /// 
+    /// Starts the LogServer's HTTP server on a background thread.
+    ///
     /// Note: The underlying implementation is based on the `Rocket` crate.
-    pub fn start_server(&self) {
+    ///
+    /// Returns a `JoinHandle` resolving to a `Result` so a caller that cares
+    /// whether the server actually came up (e.g. a bind failure surfaced as
+    /// [`LotwireError::BindFailed`] or [`LotwireError::InvalidAddress`]) can
+    /// `.join()` it; for fire-and-forget use the handle can simply be dropped.
+    pub fn start_server(&self) -> thread::JoinHandle<Result<()>> {
         // println!("Starting server");
-        thread::spawn(move || {
-            LogServer::start().unwrap();
-        });
+        thread::spawn(LogServer::start)
     }
 
     #[rocket::main]
-    async fn start() -> Result<(), rocket::Error> {
+    async fn start() -> Result<()> {
         // println!("Starting server...");
         let server = (*SERVER.lock().unwrap()).clone();
         // println!("Server: {server:?}");
-        let settings = server.settings.as_ref().unwrap().clone();
+        let settings = server
+            .settings
+            .as_ref()
+            .expect("LogServer missing settings")
+            .clone();
 
         // println!("Starting server with settings {settings:?}");
 
-        let config = rocket::Config {
-            port: settings.port as u16,
-            address: settings.address.parse().unwrap(),
-            log_level: rocket::config::LogLevel::Off,
-            cli_colors: false,
-            ..rocket::config::Config::debug_default()
-        };
+        let config = rocket_config(&settings)?;
 
-        let _ = rocket::custom(config)
+        let rocket = rocket::custom(config)
             .attach(CORS)
-            .mount("/", routes![index, logs])
-            .manage(server)
-            .launch()
-            .await?;
+            .mount("/", routes![index, logs, logs_stream])
+            .manage(server);
+
+        match unix_socket_path(&settings.address) {
+            Some(path) => start_unix_socket(rocket, path, settings.reuse).await?,
+            None => {
+                rocket.launch().await?;
+            }
+        }
 
         Ok(())
     }
+
+    /// Appends a formatted, color-free representation of `item` to the configured
+    /// log file and/or forwards it to syslog, if either sink is configured.
+    fn write_to_sinks(&self, level: Level, item: &LogItem) {
+        if self.log_file.is_none() && self.syslog.is_none() {
+            return;
+        }
+
+        let formatted = match (&item.file, item.line) {
+            (Some(file), Some(line)) => format!(
+                "{} [{}] {} ({}:{}): {}",
+                item.timestamp, item.level, item.module, file, line, item.message
+            ),
+            _ => format!(
+                "{} [{}] {}: {}",
+                item.timestamp, item.level, item.module, item.message
+            ),
+        };
+
+        if let Some(log_file) = self.log_file.as_ref() {
+            let mut log_file = log_file.lock().unwrap();
+            let _ = writeln!(log_file, "{formatted}");
+        }
+
+        if let Some(SyslogSink(logger)) = self.syslog.as_ref() {
+            let mut logger = logger.lock().unwrap();
+            let _ = match level {
+                Level::Error => logger.err(&formatted),
+                Level::Warn => logger.warning(&formatted),
+                Level::Info => logger.info(&formatted),
+                Level::Debug | Level::Trace => logger.debug(&formatted),
+            };
+        }
+    }
 }
 
 impl log::Log for LogServer {
@@ -200,10 +420,25 @@ impl log::Log for LogServer {
                 return;
             }
 
+            let extended = self
+                .settings
+                .as_ref()
+                .map(|settings| settings.extended)
+                .unwrap_or(false);
+
+            let (file, line) = if extended {
+                (record.file().map(|f| f.to_string()), record.line())
+            } else {
+                (None, None)
+            };
+
             let item = LogItem {
                 level,
                 module,
                 message,
+                timestamp: Utc::now().to_rfc3339(),
+                file,
+                line,
             };
 
             self.buffer
@@ -212,32 +447,217 @@ impl log::Log for LogServer {
                 .as_ref()
                 .lock()
                 .unwrap()
-                .push(item);
+                .push(item.clone());
+
+            if let Some(sender) = self.sender.as_ref() {
+                if sender.receiver_count() > 0 {
+                    let _ = sender.send(item.clone());
+                }
+            }
+
+            self.write_to_sinks(record.level(), &item);
         }
     }
 
     fn flush(&self) {}
 }
 
+/// Builds the `rocket::Config` for `settings`. When `settings.address` is a `unix:`
+/// socket path, the TCP address is set to localhost and the port is passed through
+/// unused, since the actual bind happens via [`start_unix_socket`] instead.
+fn rocket_config(settings: &Settings) -> Result<rocket::Config> {
+    let address = match unix_socket_path(&settings.address) {
+        Some(_) => std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+        None => settings
+            .address
+            .parse()
+            .map_err(|_| LotwireError::InvalidAddress(settings.address.clone()))?,
+    };
+
+    Ok(rocket::Config {
+        port: settings.port as u16,
+        address,
+        log_level: rocket::config::LogLevel::Off,
+        cli_colors: false,
+        ..rocket::config::Config::debug_default()
+    })
+}
+
+/// Launches `rocket` on a Unix domain socket at `path`. When `reuse` is set, a
+/// stale socket file left behind by a previous run is removed before binding,
+/// and the file is cleaned up again once the server stops.
+#[cfg(unix)]
+async fn start_unix_socket(rocket: rocket::Rocket<rocket::Build>, path: &str, reuse: bool) -> Result<()> {
+    use rocket::listener::unix::UnixListener;
+
+    if reuse {
+        let _ = std::fs::remove_file(path);
+    }
+
+    let listener = UnixListener::bind(path)
+        .await
+        .map_err(|source| LotwireError::UnixSocketBind {
+            path: path.to_string(),
+            source,
+        })?;
+
+    rocket.launch_on(listener).await?;
+
+    if reuse {
+        let _ = std::fs::remove_file(path);
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn start_unix_socket(_rocket: rocket::Rocket<rocket::Build>, path: &str, _reuse: bool) -> Result<()> {
+    Err(LotwireError::UnixSocketUnsupported(path.to_string()))
+}
+
+/// A request guard that enforces the `api_keys` configured on [`Settings`], if any.
+///
+/// When no API keys are configured the guard always succeeds, preserving the
+/// previous unauthenticated behavior of the log endpoints.
+pub struct ApiKey;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ApiKey {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(server) = request.rocket().state::<LogServer>() else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+
+        let api_keys = server
+            .settings
+            .as_ref()
+            .map(|settings| settings.api_keys.as_slice())
+            .unwrap_or(&[]);
+
+        if api_keys.is_empty() {
+            return Outcome::Success(ApiKey);
+        }
+
+        let provided = request.headers().get_one("X-Api-Key").or_else(|| {
+            request
+                .headers()
+                .get_one("Authorization")
+                .and_then(|value| value.strip_prefix("Bearer "))
+        });
+
+        match provided {
+            Some(key) if api_keys.iter().any(|configured| constant_time_eq(configured, key)) => {
+                Outcome::Success(ApiKey)
+            }
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+/// Compares two strings without branching on the position of the first mismatch,
+/// so a configured API key can't be recovered by timing a byte-by-byte guess.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
 #[get("/")]
 fn index() -> &'static str {
     "Logserver online"
 }
 
-#[get("/logs")]
-fn logs(server: &State<LogServer>) -> (Status, Json<Vec<LogItem>>) {
+/// Returns records from the in-memory ring buffer, optionally filtered by
+/// `level` (that severity or more severe), `module` (prefix match), and
+/// `contains` (case-insensitive substring match on the message), with the
+/// result capped to the most recent `limit` matching records.
+#[get("/logs?<level>&<module>&<contains>&<limit>")]
+fn logs(
+    server: &State<LogServer>,
+    _key: ApiKey,
+    level: Option<&str>,
+    module: Option<&str>,
+    contains: Option<&str>,
+    limit: Option<usize>,
+) -> (Status, Json<Vec<LogItem>>) {
+    let level = match level.map(|level| level.parse::<Level>()) {
+        Some(Ok(level)) => Some(level),
+        Some(Err(_)) => return (Status::BadRequest, Json(Vec::new())),
+        None => None,
+    };
+
     let buffer = server.buffer.as_ref().unwrap().clone();
     let buffer = buffer.lock().unwrap();
 
-    let mut log_items: Vec<LogItem> = Vec::new();
-
-    for item in buffer.iter() {
-        log_items.push(item.clone());
+    let contains = contains.map(|contains| contains.to_lowercase());
+
+    let mut log_items: Vec<LogItem> = buffer
+        .iter()
+        .filter(|item| {
+            level
+                .map(|level| item.level.parse::<Level>().map_or(true, |item_level| item_level <= level))
+                .unwrap_or(true)
+        })
+        .filter(|item| module.map_or(true, |module| item.module.starts_with(module)))
+        .filter(|item| {
+            contains
+                .as_ref()
+                .map_or(true, |contains| item.message.to_lowercase().contains(contains))
+        })
+        .cloned()
+        .collect();
+
+    if let Some(limit) = limit {
+        if log_items.len() > limit {
+            log_items.drain(..log_items.len() - limit);
+        }
     }
 
     (Status::Ok, Json(log_items))
 }
 
+/// Streams `LogItem`s to the client as Server-Sent Events as they are logged.
+///
+/// The stream is first primed with a snapshot of the current ring buffer contents,
+/// then continues with live records as they arrive. A slow consumer that falls
+/// behind the broadcast channel's capacity silently skips the messages it missed.
+#[get("/logs/stream")]
+fn logs_stream(server: &State<LogServer>, _key: ApiKey, mut end: Shutdown) -> EventStream![] {
+    // Subscribe before taking the snapshot so a record logged in between isn't
+    // lost between the two (a duplicate in the rare race is fine; a gap isn't).
+    let mut rx = server.sender.as_ref().unwrap().subscribe();
+
+    let snapshot: Vec<LogItem> = {
+        let buffer = server.buffer.as_ref().unwrap().lock().unwrap();
+        buffer.iter().cloned().collect()
+    };
+
+    EventStream! {
+        for item in snapshot {
+            yield Event::json(&item);
+        }
+
+        loop {
+            let item = select! {
+                item = rx.recv() => match item {
+                    Ok(item) => item,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                },
+                _ = &mut end => break,
+            };
+
+            yield Event::json(&item);
+        }
+    }
+}
+
 pub struct CORS;
 
 #[rocket::async_trait]
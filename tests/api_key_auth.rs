@@ -0,0 +1,62 @@
+use std::{thread, time::Duration};
+
+use lotwire::{LogServer, Settings};
+
+/// Tests that the `/logs` endpoint rejects requests without a valid API key once
+/// `api_keys` is configured, and accepts requests that present one.
+#[tokio::test]
+async fn test_api_key_guard() {
+    // -- ARRANGE
+    let settings = Settings::with_values("127.0.0.1", 8083, log::Level::Trace, 50)
+        .with_api_keys(vec!["secret".to_string()]);
+    let server = LogServer::with_settings(settings);
+    server.init_logger().expect("failed to initialize logger");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    // wait for the server to come online
+    thread::sleep(Duration::from_secs(1));
+
+    // -- ACT / ASSERT
+    // no key at all
+    let status = reqwest::get("http://127.0.0.1:8083/logs")
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+
+    let client = reqwest::Client::new();
+
+    // wrong key
+    let status = client
+        .get("http://127.0.0.1:8083/logs")
+        .header("X-Api-Key", "wrong")
+        .send()
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+
+    // correct key via X-Api-Key
+    let status = client
+        .get("http://127.0.0.1:8083/logs")
+        .header("X-Api-Key", "secret")
+        .send()
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, reqwest::StatusCode::OK);
+
+    // correct key via Authorization: Bearer
+    let status = client
+        .get("http://127.0.0.1:8083/logs")
+        .header("Authorization", "Bearer secret")
+        .send()
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, reqwest::StatusCode::OK);
+}
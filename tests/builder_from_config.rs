@@ -0,0 +1,128 @@
+use std::{fs, sync::Mutex, thread, time::Duration};
+
+use lotwire::{LogItem, LogServerBuilder};
+
+/// A logger can be installed only once per process, and `start_server` serves the
+/// most recently built server, so the tests in this file run one at a time and
+/// only the test that reads back logged records installs the logger.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Tests that `LogServerBuilder::from_config` actually applies the toggles loaded
+/// from the config file (rather than silently resetting them to their defaults),
+/// by checking that a server built this way captures extended file/line fields.
+#[tokio::test]
+async fn test_builder_from_config_preserves_extended_toggle() {
+    // -- ARRANGE
+    let _serial = SERIAL.lock().unwrap_or_else(|err| err.into_inner());
+    let dir = std::env::temp_dir().join("lotwire_builder_from_config_test");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("lotwire.toml"),
+        r#"
+address = "127.0.0.1"
+port = 8084
+level = "trace"
+num_messages = 25
+extended = true
+"#,
+    )
+    .unwrap();
+
+    let server = LogServerBuilder::from_config(dir.to_str().unwrap(), "lotwire.toml")
+        .build()
+        .expect("builder should load settings from the config file");
+    server.init_logger().expect("failed to initialize logger");
+
+    // -- ACT
+    log::error!("extended logging should capture this record's file and line");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    // wait for the server to come online
+    thread::sleep(Duration::from_secs(1));
+
+    let body = reqwest::get("http://127.0.0.1:8084/logs")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    // -- ASSERT
+    let items: Vec<LogItem> = serde_json::from_str(&body).unwrap();
+    let item = items
+        .iter()
+        .find(|item| item.message.contains("extended logging"))
+        .expect("the logged record should be present");
+
+    // `extended = true` in lotwire.toml must survive the builder's config merge.
+    assert!(item.file.is_some());
+    assert!(item.line.is_some());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+/// Tests that `reuse = true` loaded from the config file actually reaches the
+/// server: with reuse enabled, a stale file left at the unix socket path is
+/// removed before bind, so the server can still come up and serve requests.
+/// Before the builder's config-merge bug was fixed, `reuse` was silently
+/// forced back to `false` and this bind would have failed instead.
+#[cfg(unix)]
+#[tokio::test]
+async fn test_builder_from_config_preserves_reuse_toggle() {
+    use std::{
+        io::{Read, Write},
+        os::unix::net::UnixStream,
+    };
+
+    // -- ARRANGE
+    let _serial = SERIAL.lock().unwrap_or_else(|err| err.into_inner());
+    let dir = std::env::temp_dir().join("lotwire_builder_from_config_reuse_test");
+    fs::create_dir_all(&dir).unwrap();
+    let socket_path = dir.join("lotwire.sock");
+    fs::write(&socket_path, b"stale").expect("failed to create a stale placeholder file");
+    fs::write(
+        dir.join("lotwire.toml"),
+        format!(
+            r#"
+address = "unix:{}"
+port = 0
+level = "trace"
+num_messages = 25
+reuse = true
+"#,
+            socket_path.to_str().unwrap()
+        ),
+    )
+    .unwrap();
+
+    let server = LogServerBuilder::from_config(dir.to_str().unwrap(), "lotwire.toml")
+        .build()
+        .expect("builder should load settings from the config file");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    // wait for the stale file to be replaced by the real socket
+    thread::sleep(Duration::from_secs(1));
+
+    // -- ACT
+    let mut stream =
+        UnixStream::connect(&socket_path).expect("reuse should have removed the stale file and bound the socket");
+    stream
+        .write_all(b"GET /logs HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    // -- ASSERT
+    assert!(response.starts_with("HTTP/1.1 200"));
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -12,7 +12,7 @@ async fn test_has_logs() {
     // configure the server with the specified settings and start it
     let settings = Settings::with_values("127.0.0.1", 8080, log::Level::Trace, 50);
     let server = LogServer::with_settings(settings);
-    server.init_logger();
+    server.init_logger().expect("failed to initialize logger");
 
     // -- ACT
     // record some example log items
@@ -0,0 +1,33 @@
+use std::{fs, thread, time::Duration};
+
+use log::error;
+use lotwire::{LogServer, Settings};
+
+/// Tests that a configured `log_file` actually receives the formatted,
+/// ANSI-free log line for each record, in addition to the in-memory buffer.
+#[tokio::test]
+async fn test_log_file_sink_receives_formatted_records() {
+    // -- ARRANGE
+    let path = std::env::temp_dir().join("lotwire_log_file_sink_test.log");
+    let _ = fs::remove_file(&path);
+
+    let settings = Settings::with_values("127.0.0.1", 8086, log::Level::Trace, 50)
+        .with_log_file(path.to_str().unwrap());
+    let server = LogServer::with_settings(settings);
+    server.init_logger().expect("failed to initialize logger");
+
+    // -- ACT
+    error!("written to the configured log file");
+
+    // give the logger a moment to flush the write to disk
+    thread::sleep(Duration::from_millis(200));
+
+    // -- ASSERT
+    let contents = fs::read_to_string(&path).expect("log file should have been created");
+    assert!(contents.contains("written to the configured log file"));
+    assert!(contents.contains("ERROR"));
+    // the formatted line must not carry ANSI color codes
+    assert!(!contents.contains("\u{1b}["));
+
+    fs::remove_file(&path).ok();
+}
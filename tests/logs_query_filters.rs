@@ -0,0 +1,67 @@
+use std::{thread, time::Duration};
+
+use log::{error, info, warn};
+use lotwire::{LogItem, LogServer, Settings};
+
+/// Exercises the `level`/`module`/`contains`/`limit` query parameters on `/logs`.
+#[tokio::test]
+async fn test_logs_query_filters() {
+    // -- ARRANGE
+    let settings = Settings::with_values("127.0.0.1", 8082, log::Level::Trace, 50);
+    let server = LogServer::with_settings(settings);
+    server.init_logger().expect("failed to initialize logger");
+
+    // -- ACT
+    info!("just chatting");
+    warn!(target: "myapp::db", "connection timeout");
+    error!("a critical failure");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+    thread::sleep(Duration::from_secs(1));
+
+    // -- ASSERT
+    // level=warn keeps records at warn severity or higher, drops info
+    let body = reqwest::get("http://127.0.0.1:8082/logs?level=warn")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("connection timeout"));
+    assert!(body.contains("a critical failure"));
+    assert!(!body.contains("just chatting"));
+
+    // module is a prefix match
+    let body = reqwest::get("http://127.0.0.1:8082/logs?module=myapp::db")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("connection timeout"));
+    assert!(!body.contains("a critical failure"));
+
+    // contains is a case-insensitive substring match
+    let body = reqwest::get("http://127.0.0.1:8082/logs?contains=TIMEOUT")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("connection timeout"));
+    assert!(!body.contains("a critical failure"));
+
+    // limit returns only the most recent N matching records
+    let body = reqwest::get("http://127.0.0.1:8082/logs?limit=1")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let items: Vec<LogItem> = serde_json::from_str(&body).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].message, "a critical failure");
+}
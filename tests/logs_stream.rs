@@ -0,0 +1,50 @@
+use std::{thread, time::Duration};
+
+use futures_util::StreamExt;
+use log::error;
+use lotwire::{LogServer, Settings};
+
+/// Tests that a client connected to `/logs/stream` receives a record logged
+/// after it connects, over the live SSE broadcast (not just the initial snapshot).
+#[tokio::test]
+async fn test_logs_stream_receives_live_records() {
+    // -- ARRANGE
+    let settings = Settings::with_values("127.0.0.1", 8085, log::Level::Trace, 50);
+    let server = LogServer::with_settings(settings);
+    server.init_logger().expect("failed to initialize logger");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    // wait for the server to come online
+    thread::sleep(Duration::from_secs(1));
+
+    let response = reqwest::get("http://127.0.0.1:8085/logs/stream")
+        .await
+        .unwrap();
+    let mut stream = response.bytes_stream();
+
+    // give the connection a moment to register as a subscriber before logging
+    thread::sleep(Duration::from_millis(200));
+
+    // -- ACT
+    error!("a record logged after the client connected");
+
+    // -- ASSERT
+    let received = tokio::time::timeout(Duration::from_secs(5), async {
+        let mut body = String::new();
+        while let Some(chunk) = stream.next().await {
+            body.push_str(&String::from_utf8_lossy(&chunk.unwrap()));
+            if body.contains("a record logged after the client connected") {
+                break;
+            }
+        }
+        body
+    })
+    .await
+    .expect("timed out waiting for the stream to deliver the new record");
+
+    assert!(received.contains("a record logged after the client connected"));
+}
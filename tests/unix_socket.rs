@@ -0,0 +1,51 @@
+#![cfg(unix)]
+
+use std::{
+    fs,
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+    thread,
+    time::Duration,
+};
+
+use lotwire::{LogServer, Settings};
+
+/// Tests that a `unix:` address binds a Unix domain socket that serves the
+/// `/logs` endpoint, and that `reuse` removes a stale file left at that path
+/// so the bind can succeed.
+#[tokio::test]
+async fn test_unix_socket_serves_logs_and_reuses_stale_path() {
+    // -- ARRANGE
+    let path = std::env::temp_dir().join("lotwire_unix_socket_test.sock");
+    let _ = fs::remove_file(&path);
+    fs::write(&path, b"stale").expect("failed to create a stale placeholder file");
+
+    let address = format!("unix:{}", path.to_str().unwrap());
+    let settings =
+        Settings::with_values(&address, 0, log::Level::Trace, 50).with_reuse(true);
+    let server = LogServer::with_settings(settings);
+    server.init_logger().expect("failed to initialize logger");
+
+    thread::spawn(move || {
+        server.start_server();
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    // wait for the stale file to be replaced by the real socket
+    thread::sleep(Duration::from_secs(1));
+
+    // -- ACT
+    let mut stream =
+        UnixStream::connect(&path).expect("reuse should have removed the stale file and bound the socket");
+    stream
+        .write_all(b"GET /logs HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    // -- ASSERT
+    assert!(response.starts_with("HTTP/1.1 200"));
+
+    fs::remove_file(&path).ok();
+}